@@ -17,6 +17,10 @@ pub enum StackError {
     RequirementNotSatisfied,
     #[error("inner operation error")]
     InnerOperationError(#[from] Box<OperationError>),
+    #[error("could not convert \"{value}\" to {to:?}")]
+    ConversionError { value: String, to: ConversionKind },
+    #[error("value is not numeric: \"{0}\"")]
+    NotNumericError(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -33,6 +37,147 @@ impl Default for CloneMode {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversionKind {
+    Bytes,
+    Integer,
+    Float,
+    Boolean,
+    Timestamp,
+    TimestampFmt(String),
+    TimestampTzFmt(String),
+}
+
+impl ConversionKind {
+    fn convert<'a>(&self, value: Cow<'a, str>) -> Result<Cow<'a, str>, StackError> {
+        let err = || StackError::ConversionError {
+            value: value.to_string(),
+            to: self.clone(),
+        };
+
+        match self {
+            Self::Bytes => Ok(value),
+            Self::Integer => {
+                let n: i64 = value.parse().map_err(|_| err())?;
+                let canonical = n.to_string();
+                if canonical == value.as_ref() {
+                    Ok(value)
+                } else {
+                    Ok(Cow::Owned(canonical))
+                }
+            }
+            Self::Float => {
+                let n: f64 = value.parse().map_err(|_| err())?;
+                if !n.is_finite() {
+                    return Err(err());
+                }
+                let canonical = n.to_string();
+                if canonical == value.as_ref() {
+                    Ok(value)
+                } else {
+                    Ok(Cow::Owned(canonical))
+                }
+            }
+            Self::Boolean => {
+                let canonical = match value.as_ref() {
+                    "true" | "1" => "true",
+                    "false" | "0" => "false",
+                    _ => return Err(err()),
+                };
+                if canonical == value.as_ref() {
+                    Ok(value)
+                } else {
+                    Ok(Cow::Owned(canonical.to_string()))
+                }
+            }
+            Self::Timestamp => {
+                let secs: i64 = value.parse().map_err(|_| err())?;
+                let dt = chrono::DateTime::from_timestamp(secs, 0).ok_or_else(err)?;
+                Ok(Cow::Owned(
+                    dt.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                ))
+            }
+            Self::TimestampFmt(fmt) => {
+                let naive = chrono::NaiveDateTime::parse_from_str(value.as_ref(), fmt)
+                    .map_err(|_| err())?;
+                Ok(Cow::Owned(
+                    naive
+                        .and_utc()
+                        .to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true),
+                ))
+            }
+            Self::TimestampTzFmt(fmt) => {
+                let dt =
+                    chrono::DateTime::parse_from_str(value.as_ref(), fmt).map_err(|_| err())?;
+                Ok(Cow::Owned(
+                    dt.with_timezone(&chrono::Utc)
+                        .to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true),
+                ))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversionErrorMode {
+    Fail,
+    Drop,
+}
+
+impl Default for ConversionErrorMode {
+    fn default() -> Self {
+        Self::Fail
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        Self::Ascending
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortKey {
+    Lexicographic,
+    CaseInsensitive,
+    Numeric,
+}
+
+impl Default for SortKey {
+    fn default() -> Self {
+        Self::Lexicographic
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FiniteF64(f64);
+
+impl Eq for FiniteF64 {}
+
+impl Ord for FiniteF64 {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0
+            .partial_cmp(&other.0)
+            .expect("FiniteF64 values are validated finite before construction")
+    }
+}
+
+impl PartialOrd for FiniteF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Stack {
@@ -72,6 +217,27 @@ pub enum Stack {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<String>,
     },
+    Convert {
+        to: ConversionKind,
+        #[serde(default)]
+        on_error: ConversionErrorMode,
+    },
+    Sort {
+        #[serde(default)]
+        order: SortOrder,
+        #[serde(default)]
+        by: SortKey,
+    },
+    Unique {
+        #[serde(default)]
+        adjacent: bool,
+    },
+    Branch {
+        cond: Vec<super::Operation>,
+        then: Vec<super::Operation>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        otherwise: Option<Vec<super::Operation>>,
+    },
 }
 
 impl Stack {
@@ -218,6 +384,89 @@ impl Stack {
                 );
                 input
             }
+            Self::Convert { to, on_error } => {
+                let mut out = Vec::with_capacity(input.len());
+                for value in input.into_iter() {
+                    match to.convert(value) {
+                        Ok(v) => out.push(v),
+                        Err(e) => match on_error {
+                            ConversionErrorMode::Fail => return Err(e),
+                            ConversionErrorMode::Drop => {}
+                        },
+                    }
+                }
+                out
+            }
+            Self::Sort { order, by } => {
+                match by {
+                    SortKey::Lexicographic => input.sort(),
+                    SortKey::CaseInsensitive => input.sort_by_cached_key(|v| v.to_lowercase()),
+                    SortKey::Numeric => {
+                        for v in &input {
+                            let n: f64 = v
+                                .parse()
+                                .map_err(|_| StackError::NotNumericError(v.to_string()))?;
+                            if !n.is_finite() {
+                                return Err(StackError::NotNumericError(v.to_string()));
+                            }
+                        }
+                        input.sort_by_cached_key(|v| {
+                            FiniteF64(v.parse().expect("validated numeric and finite above"))
+                        });
+                    }
+                }
+
+                if *order == SortOrder::Descending {
+                    input.reverse();
+                }
+
+                input
+            }
+            Self::Unique { adjacent } => {
+                if *adjacent {
+                    input.dedup();
+                    input
+                } else {
+                    use std::collections::HashSet;
+
+                    let mut seen: HashSet<&str> = HashSet::with_capacity(input.len());
+                    let keep = input
+                        .iter()
+                        .map(|v| seen.insert(v.as_ref()))
+                        .collect::<Vec<_>>();
+
+                    input
+                        .into_iter()
+                        .zip(keep)
+                        .filter_map(|(v, keep)| keep.then_some(v))
+                        .collect()
+                }
+            }
+            Self::Branch {
+                cond,
+                then,
+                otherwise,
+            } => {
+                let cond_ops = cond.iter().collect::<Vec<_>>();
+                let satisfied =
+                    super::process_operations(input.clone(), cond_ops.as_slice()).is_ok();
+
+                if satisfied {
+                    let then_ops = then.iter().collect::<Vec<_>>();
+                    match super::process_operations(input, then_ops.as_slice()) {
+                        Ok(v) => v,
+                        Err(e) => return Err(StackError::InnerOperationError(Box::new(e))),
+                    }
+                } else if let Some(otherwise) = otherwise {
+                    let otherwise_ops = otherwise.iter().collect::<Vec<_>>();
+                    match super::process_operations(input, otherwise_ops.as_slice()) {
+                        Ok(v) => v,
+                        Err(e) => return Err(StackError::InnerOperationError(Box::new(e))),
+                    }
+                } else {
+                    input
+                }
+            }
         };
 
         if res.is_empty() {
@@ -300,3 +549,240 @@ mod indexing {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cow(s: &str) -> Cow<'_, str> {
+        Cow::Borrowed(s)
+    }
+
+    #[test]
+    fn convert_integer_normalizes_leading_zeroes() {
+        let op = Stack::Convert {
+            to: ConversionKind::Integer,
+            on_error: ConversionErrorMode::Fail,
+        };
+
+        let res = op.process(vec![cow("007"), cow("-3")]).unwrap();
+        assert_eq!(res, vec![Cow::from("7"), Cow::from("-3")]);
+    }
+
+    #[test]
+    fn convert_integer_fails_on_bad_input() {
+        let op = Stack::Convert {
+            to: ConversionKind::Integer,
+            on_error: ConversionErrorMode::Fail,
+        };
+
+        assert!(matches!(
+            op.process(vec![cow("not-a-number")]),
+            Err(StackError::ConversionError { .. })
+        ));
+    }
+
+    #[test]
+    fn convert_on_error_drop_removes_bad_values() {
+        let op = Stack::Convert {
+            to: ConversionKind::Integer,
+            on_error: ConversionErrorMode::Drop,
+        };
+
+        let res = op.process(vec![cow("1"), cow("nope"), cow("2")]).unwrap();
+        assert_eq!(res, vec![Cow::from("1"), Cow::from("2")]);
+    }
+
+    #[test]
+    fn convert_boolean_normalizes_numeric_spellings() {
+        let op = Stack::Convert {
+            to: ConversionKind::Boolean,
+            on_error: ConversionErrorMode::Fail,
+        };
+
+        let res = op.process(vec![cow("1"), cow("0"), cow("true")]).unwrap();
+        assert_eq!(
+            res,
+            vec![Cow::from("true"), Cow::from("false"), Cow::from("true")]
+        );
+    }
+
+    #[test]
+    fn convert_timestamp_renders_rfc3339() {
+        let op = Stack::Convert {
+            to: ConversionKind::Timestamp,
+            on_error: ConversionErrorMode::Fail,
+        };
+
+        let res = op.process(vec![cow("0")]).unwrap();
+        assert_eq!(res, vec![Cow::from("1970-01-01T00:00:00Z")]);
+    }
+
+    #[test]
+    fn convert_bytes_passes_values_through_unchanged() {
+        let op = Stack::Convert {
+            to: ConversionKind::Bytes,
+            on_error: ConversionErrorMode::Fail,
+        };
+
+        let res = op.process(vec![cow("anything at all")]).unwrap();
+        assert_eq!(res, vec![Cow::from("anything at all")]);
+    }
+
+    #[test]
+    fn convert_float_normalizes_representation() {
+        let op = Stack::Convert {
+            to: ConversionKind::Float,
+            on_error: ConversionErrorMode::Fail,
+        };
+
+        let res = op.process(vec![cow("1.50"), cow("-2.5")]).unwrap();
+        assert_eq!(res, vec![Cow::from("1.5"), Cow::from("-2.5")]);
+    }
+
+    #[test]
+    fn convert_float_rejects_non_finite_values() {
+        let op = Stack::Convert {
+            to: ConversionKind::Float,
+            on_error: ConversionErrorMode::Fail,
+        };
+
+        for input in ["NaN", "inf", "infinity", "-infinity"] {
+            assert!(
+                matches!(
+                    op.process(vec![cow(input)]),
+                    Err(StackError::ConversionError { .. })
+                ),
+                "expected {input:?} to be rejected as non-finite"
+            );
+        }
+    }
+
+    #[test]
+    fn convert_timestamp_fmt_preserves_subsecond_precision() {
+        let op = Stack::Convert {
+            to: ConversionKind::TimestampFmt("%Y-%m-%dT%H:%M:%S%.f".to_string()),
+            on_error: ConversionErrorMode::Fail,
+        };
+
+        let res = op.process(vec![cow("2021-01-01T00:00:00.500")]).unwrap();
+        assert_eq!(res, vec![Cow::from("2021-01-01T00:00:00.500Z")]);
+    }
+
+    #[test]
+    fn convert_timestamp_tz_fmt_renders_utc_rfc3339() {
+        let op = Stack::Convert {
+            to: ConversionKind::TimestampTzFmt("%Y-%m-%dT%H:%M:%S%.f%:z".to_string()),
+            on_error: ConversionErrorMode::Fail,
+        };
+
+        let res = op
+            .process(vec![cow("2021-01-01T00:00:00.500+02:00")])
+            .unwrap();
+        assert_eq!(res, vec![Cow::from("2020-12-31T22:00:00.500Z")]);
+    }
+
+    #[test]
+    fn sort_numeric_orders_by_value() {
+        let op = Stack::Sort {
+            order: SortOrder::Ascending,
+            by: SortKey::Numeric,
+        };
+
+        let res = op.process(vec![cow("3"), cow("1"), cow("2")]).unwrap();
+        assert_eq!(res, vec![Cow::from("1"), Cow::from("2"), Cow::from("3")]);
+    }
+
+    #[test]
+    fn sort_numeric_rejects_non_finite_values() {
+        let op = Stack::Sort {
+            order: SortOrder::Ascending,
+            by: SortKey::Numeric,
+        };
+
+        assert!(matches!(
+            op.process(vec![cow("3"), cow("NaN"), cow("1"), cow("2")]),
+            Err(StackError::NotNumericError(_))
+        ));
+        assert!(matches!(
+            op.process(vec![cow("1"), cow("inf")]),
+            Err(StackError::NotNumericError(_))
+        ));
+    }
+
+    #[test]
+    fn sort_case_insensitive_ignores_case() {
+        let op = Stack::Sort {
+            order: SortOrder::Ascending,
+            by: SortKey::CaseInsensitive,
+        };
+
+        let res = op.process(vec![cow("banana"), cow("Apple")]).unwrap();
+        assert_eq!(res, vec![Cow::from("Apple"), Cow::from("banana")]);
+    }
+
+    #[test]
+    fn unique_non_adjacent_preserves_first_seen_order() {
+        let op = Stack::Unique { adjacent: false };
+
+        let res = op
+            .process(vec![cow("a"), cow("b"), cow("a"), cow("c")])
+            .unwrap();
+        assert_eq!(res, vec![Cow::from("a"), Cow::from("b"), Cow::from("c")]);
+    }
+
+    #[test]
+    fn unique_adjacent_only_collapses_runs() {
+        let op = Stack::Unique { adjacent: true };
+
+        let res = op
+            .process(vec![cow("a"), cow("a"), cow("b"), cow("a")])
+            .unwrap();
+        assert_eq!(res, vec![Cow::from("a"), Cow::from("b"), Cow::from("a")]);
+    }
+
+    #[test]
+    fn branch_runs_then_when_cond_succeeds() {
+        let op = Stack::Branch {
+            cond: vec![super::super::Operation::Stack(Stack::Convert {
+                to: ConversionKind::Integer,
+                on_error: ConversionErrorMode::Fail,
+            })],
+            then: vec![super::super::Operation::Stack(Stack::Join("+".to_string()))],
+            otherwise: Some(vec![super::super::Operation::Stack(Stack::Reverse)]),
+        };
+
+        let res = op.process(vec![cow("1"), cow("2")]).unwrap();
+        assert_eq!(res, vec![Cow::from("1+2")]);
+    }
+
+    #[test]
+    fn branch_runs_otherwise_when_cond_fails() {
+        let op = Stack::Branch {
+            cond: vec![super::super::Operation::Stack(Stack::Convert {
+                to: ConversionKind::Integer,
+                on_error: ConversionErrorMode::Fail,
+            })],
+            then: vec![super::super::Operation::Stack(Stack::Join("+".to_string()))],
+            otherwise: Some(vec![super::super::Operation::Stack(Stack::Reverse)]),
+        };
+
+        let res = op.process(vec![cow("a"), cow("b")]).unwrap();
+        assert_eq!(res, vec![Cow::from("b"), Cow::from("a")]);
+    }
+
+    #[test]
+    fn branch_passes_through_when_cond_fails_and_no_otherwise() {
+        let op = Stack::Branch {
+            cond: vec![super::super::Operation::Stack(Stack::Convert {
+                to: ConversionKind::Integer,
+                on_error: ConversionErrorMode::Fail,
+            })],
+            then: vec![super::super::Operation::Stack(Stack::Join("+".to_string()))],
+            otherwise: None,
+        };
+
+        let res = op.process(vec![cow("a"), cow("b")]).unwrap();
+        assert_eq!(res, vec![Cow::from("a"), Cow::from("b")]);
+    }
+}